@@ -0,0 +1,430 @@
+//! Shared retry for the data requests of the free/keyed upstreams (Yahoo
+//! chart, Reddit search, Bluesky search): exponential backoff with jitter on
+//! 429 and 5xx, honoring `Retry-After` / `X-Ratelimit-Reset` when the server
+//! sends one, behind a per-host cap on requests in flight.
+//!
+//! Deliberately NOT used by the X pulse — reads there are paid, and the user
+//! approved one call, not a retry loop. Auth calls aren't retried either
+//! (Bluesky's createSession limit is tight; a retry storm there locks the
+//! account out for longer than it saves).
+
+use std::collections::hash_map::RandomState;
+use std::future::Future;
+use std::hash::{BuildHasher, Hasher};
+use std::sync::Arc;
+use std::time::Duration;
+
+use reqwest::header::HeaderMap;
+use reqwest::{RequestBuilder, Response, StatusCode};
+use tokio::sync::Semaphore;
+
+/// Total tries per request, including the first.
+pub(crate) const MAX_ATTEMPTS: u32 = 3;
+const BASE_DELAY: Duration = Duration::from_millis(500);
+/// Longest single wait. A server hint beyond this is surfaced to the caller
+/// as the final response instead of stalling the report.
+const MAX_DELAY: Duration = Duration::from_secs(8);
+/// Requests one adapter keeps in flight against its host. A watchlist scan
+/// fans out one request per ticker per source; this turns the burst (and any
+/// retries) into a queue instead of a thundering herd.
+const MAX_IN_FLIGHT_PER_HOST: usize = 4;
+
+/// Per-host concurrency gate. Each adapter owns one, so cloned adapters
+/// (e.g. the MCP server's) share it.
+#[derive(Clone)]
+pub(crate) struct HostGate(Arc<Semaphore>);
+
+impl Default for HostGate {
+    fn default() -> Self {
+        Self(Arc::new(Semaphore::new(MAX_IN_FLIGHT_PER_HOST)))
+    }
+}
+
+/// Throttling and server-side failures are transient; anything else
+/// (4xx auth/validation, 2xx) is final.
+pub(crate) fn is_retryable(status: StatusCode) -> bool {
+    status == StatusCode::TOO_MANY_REQUESTS || status.is_server_error()
+}
+
+/// How long the server asked us to wait: `Retry-After` in its delta-seconds
+/// form on any status (the HTTP-date form is rare on these APIs and is
+/// treated as absent). Failing that, Reddit's `X-Ratelimit-Reset` — seconds
+/// left in its 600s window, sometimes a decimal — but only once the quota is
+/// actually spent (429, or `X-Ratelimit-Remaining: 0`). Reddit sends it on
+/// every response, so reading it on a 5xx would turn a transient server
+/// error into an over-the-cap "wait" and skip the retry.
+pub(crate) fn retry_after(status: StatusCode, headers: &HeaderMap) -> Option<Duration> {
+    let header = |name: &str| headers.get(name).and_then(|v| v.to_str().ok());
+    let secs = |name: &str| header(name).and_then(|s| s.trim().parse::<f64>().ok());
+    if let Some(secs) = header("retry-after").and_then(|s| s.trim().parse::<u64>().ok()) {
+        return Some(Duration::from_secs(secs));
+    }
+    let quota_spent =
+        status == StatusCode::TOO_MANY_REQUESTS || secs("x-ratelimit-remaining") == Some(0.0);
+    if !quota_spent {
+        return None;
+    }
+    secs("x-ratelimit-reset").and_then(|secs| Duration::try_from_secs_f64(secs).ok())
+}
+
+/// Wait before retry number `attempt` (1-based): the server's hint if given,
+/// else `BASE_DELAY · 2^(attempt-1)` stretched by up to 25% per
+/// `jitter ∈ [0, 1)`. None = the wait would exceed `MAX_DELAY`; give up.
+pub(crate) fn backoff(
+    attempt: u32,
+    retry_after: Option<Duration>,
+    jitter: f64,
+) -> Option<Duration> {
+    let wait = match retry_after {
+        Some(d) => d,
+        None => {
+            let exp = BASE_DELAY.saturating_mul(1u32 << attempt.saturating_sub(1).min(16));
+            exp.mul_f64(1.0 + 0.25 * jitter.clamp(0.0, 1.0))
+        }
+    };
+    (wait <= MAX_DELAY).then_some(wait)
+}
+
+/// Uniform-ish `[0, 1)` from the std hasher's per-instance random keys —
+/// enough to de-synchronize concurrent scans without a `rand` dependency.
+fn jitter() -> f64 {
+    let bits = RandomState::new().build_hasher().finish();
+    (bits >> 11) as f64 / (1u64 << 53) as f64
+}
+
+/// What the retry loop needs to know about a response.
+pub(crate) trait Attempt {
+    fn status(&self) -> StatusCode;
+    fn headers(&self) -> &HeaderMap;
+}
+
+impl Attempt for Response {
+    fn status(&self) -> StatusCode {
+        self.status()
+    }
+    fn headers(&self) -> &HeaderMap {
+        self.headers()
+    }
+}
+
+/// Send `request` through `gate`, retrying on 429/5xx per `backoff`.
+/// Transport errors are returned immediately (the client timeout has already
+/// been spent). The final response is returned as-is, so callers keep their
+/// status mapping. The permit is held across the backoff sleeps, so a
+/// throttled host sees fewer requests, not the same burst again.
+pub(crate) async fn send_with_retry(
+    request: RequestBuilder,
+    gate: &HostGate,
+) -> Result<Response, reqwest::Error> {
+    // The semaphore is never closed, so acquire can't fail.
+    let _permit = gate.0.acquire().await.ok();
+    // Bodiless GETs always clone; a streaming body can't be replayed, so it
+    // is sent once as-is.
+    let Some(first) = request.try_clone() else {
+        return request.send().await;
+    };
+    retry(
+        first.send(),
+        || request.try_clone().map(RequestBuilder::send),
+        jitter,
+    )
+    .await
+}
+
+/// The loop behind `send_with_retry`, generic over the send so it can be
+/// tested without a server. `again` returning None (request not
+/// replayable) ends the loop with the response in hand.
+async fn retry<R, E, Fut>(
+    first: Fut,
+    mut again: impl FnMut() -> Option<Fut>,
+    mut jitter: impl FnMut() -> f64,
+) -> Result<R, E>
+where
+    R: Attempt,
+    Fut: Future<Output = Result<R, E>>,
+{
+    let mut resp = first.await?;
+    for attempt in 1..MAX_ATTEMPTS {
+        if !is_retryable(resp.status()) {
+            break;
+        }
+        let Some(wait) = backoff(
+            attempt,
+            retry_after(resp.status(), resp.headers()),
+            jitter(),
+        ) else {
+            break;
+        };
+        let Some(next) = again() else {
+            break;
+        };
+        tokio::time::sleep(wait).await;
+        resp = next.await?;
+    }
+    Ok(resp)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn retries_only_throttling_and_server_errors() {
+        assert!(is_retryable(StatusCode::TOO_MANY_REQUESTS));
+        assert!(is_retryable(StatusCode::INTERNAL_SERVER_ERROR));
+        assert!(is_retryable(StatusCode::SERVICE_UNAVAILABLE));
+        assert!(!is_retryable(StatusCode::OK));
+        assert!(!is_retryable(StatusCode::BAD_REQUEST));
+        assert!(!is_retryable(StatusCode::UNAUTHORIZED));
+        assert!(!is_retryable(StatusCode::NOT_FOUND));
+    }
+
+    #[test]
+    fn backoff_doubles_and_jitter_stretches_up_to_a_quarter() {
+        assert_eq!(backoff(1, None, 0.0), Some(Duration::from_millis(500)));
+        assert_eq!(backoff(2, None, 0.0), Some(Duration::from_millis(1000)));
+        assert_eq!(backoff(3, None, 0.0), Some(Duration::from_millis(2000)));
+        assert_eq!(backoff(2, None, 1.0), Some(Duration::from_millis(1250)));
+    }
+
+    #[test]
+    fn backoff_honors_retry_after_and_gives_up_past_the_cap() {
+        assert_eq!(
+            backoff(1, Some(Duration::from_secs(3)), 0.9),
+            Some(Duration::from_secs(3))
+        );
+        assert_eq!(backoff(1, Some(Duration::from_secs(60)), 0.0), None);
+        // Exponential growth alone also hits the cap instead of overflowing.
+        assert_eq!(backoff(40, None, 0.0), None);
+    }
+
+    fn headers(pairs: &[(&'static str, &str)]) -> HeaderMap {
+        let mut h = HeaderMap::new();
+        for (k, v) in pairs {
+            h.insert(*k, v.parse().unwrap());
+        }
+        h
+    }
+
+    const TOO_MANY: StatusCode = StatusCode::TOO_MANY_REQUESTS;
+    const UNAVAILABLE: StatusCode = StatusCode::SERVICE_UNAVAILABLE;
+
+    #[test]
+    fn retry_after_applies_to_any_status() {
+        for status in [TOO_MANY, UNAVAILABLE] {
+            assert_eq!(
+                retry_after(status, &headers(&[("retry-after", "3")])),
+                Some(Duration::from_secs(3))
+            );
+        }
+        assert_eq!(
+            retry_after(
+                TOO_MANY,
+                &headers(&[("retry-after", "2"), ("x-ratelimit-reset", "40")])
+            ),
+            Some(Duration::from_secs(2))
+        );
+        assert_eq!(
+            retry_after(
+                TOO_MANY,
+                &headers(&[("retry-after", "Wed, 21 Oct 2026 07:28:00 GMT")])
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn reddit_reset_counts_only_once_the_quota_is_spent() {
+        assert_eq!(
+            retry_after(TOO_MANY, &headers(&[("x-ratelimit-reset", "42")])),
+            Some(Duration::from_secs(42))
+        );
+        assert_eq!(
+            retry_after(
+                UNAVAILABLE,
+                &headers(&[
+                    ("x-ratelimit-remaining", "0.0"),
+                    ("x-ratelimit-reset", "1.5")
+                ])
+            ),
+            Some(Duration::from_millis(1500))
+        );
+        // Reset rides along on every Reddit response; with quota left it is
+        // just the window clock, not a request to wait.
+        assert_eq!(
+            retry_after(
+                UNAVAILABLE,
+                &headers(&[
+                    ("x-ratelimit-remaining", "412"),
+                    ("x-ratelimit-reset", "300")
+                ])
+            ),
+            None
+        );
+        assert_eq!(
+            retry_after(UNAVAILABLE, &headers(&[("x-ratelimit-reset", "300")])),
+            None
+        );
+        assert_eq!(
+            retry_after(TOO_MANY, &headers(&[("x-ratelimit-reset", "-1")])),
+            None
+        );
+    }
+
+    #[test]
+    fn reddit_window_reset_past_the_cap_gives_up() {
+        // Spent quota: nothing succeeds until the window resets, so no retry.
+        let hint = retry_after(TOO_MANY, &headers(&[("x-ratelimit-reset", "37")]));
+        assert_eq!(backoff(1, hint, 0.0), None);
+    }
+
+    #[test]
+    fn jitter_is_in_unit_interval() {
+        for _ in 0..100 {
+            let j = jitter();
+            assert!((0.0..1.0).contains(&j), "jitter {j}");
+        }
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct Fake(StatusCode, HeaderMap);
+
+    impl Attempt for Fake {
+        fn status(&self) -> StatusCode {
+            self.0
+        }
+        fn headers(&self) -> &HeaderMap {
+            &self.1
+        }
+    }
+
+    fn reply(status: StatusCode, pairs: &[(&'static str, &str)]) -> Fake {
+        Fake(status, headers(pairs))
+    }
+
+    type Reply = std::future::Ready<Result<Fake, &'static str>>;
+
+    /// Scripted responses; `again` counts how many retries were sent.
+    async fn run(
+        script: Vec<Result<Fake, &'static str>>,
+        replayable: bool,
+    ) -> (Result<Fake, &'static str>, usize) {
+        let mut script = script.into_iter();
+        let first: Reply = std::future::ready(script.next().unwrap());
+        let mut retries = 0;
+        let out = retry(
+            first,
+            || {
+                if !replayable {
+                    return None;
+                }
+                retries += 1;
+                Some(std::future::ready(script.next().unwrap()))
+            },
+            || 0.0,
+        )
+        .await;
+        (out, retries)
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn success_is_sent_once() {
+        let (out, retries) = run(vec![Ok(reply(StatusCode::OK, &[]))], true).await;
+        assert_eq!(out, Ok(reply(StatusCode::OK, &[])));
+        assert_eq!(retries, 0);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn final_status_is_not_retried() {
+        let (out, retries) = run(vec![Ok(reply(StatusCode::UNAUTHORIZED, &[]))], true).await;
+        assert_eq!(out, Ok(reply(StatusCode::UNAUTHORIZED, &[])));
+        assert_eq!(retries, 0);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn recovers_after_transient_failures() {
+        let (out, retries) = run(
+            vec![
+                Ok(reply(StatusCode::SERVICE_UNAVAILABLE, &[])),
+                Ok(reply(TOO_MANY, &[])),
+                Ok(reply(StatusCode::OK, &[])),
+            ],
+            true,
+        )
+        .await;
+        assert_eq!(out, Ok(reply(StatusCode::OK, &[])));
+        assert_eq!(retries, 2);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn stops_at_max_attempts_and_returns_last_response_unchanged() {
+        let hint = [("retry-after", "1")];
+        let (out, retries) = run(
+            vec![
+                Ok(reply(TOO_MANY, &[])),
+                Ok(reply(StatusCode::BAD_GATEWAY, &[])),
+                Ok(reply(TOO_MANY, &hint)),
+                Ok(reply(StatusCode::OK, &[])),
+            ],
+            true,
+        )
+        .await;
+        assert_eq!(out, Ok(reply(TOO_MANY, &hint)));
+        assert_eq!(retries, MAX_ATTEMPTS as usize - 1);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn oversized_wait_hint_gives_up_without_retrying() {
+        let hint = [("retry-after", "60")];
+        let (out, retries) = run(vec![Ok(reply(TOO_MANY, &hint))], true).await;
+        assert_eq!(out, Ok(reply(TOO_MANY, &hint)));
+        assert_eq!(retries, 0);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn reddit_5xx_with_window_clock_still_backs_off_exponentially() {
+        let window = [
+            ("x-ratelimit-remaining", "412"),
+            ("x-ratelimit-reset", "300"),
+        ];
+        let started = tokio::time::Instant::now();
+        let (out, retries) = run(
+            vec![
+                Ok(reply(UNAVAILABLE, &window)),
+                Ok(reply(StatusCode::BAD_GATEWAY, &window)),
+                Ok(reply(StatusCode::OK, &window)),
+            ],
+            true,
+        )
+        .await;
+        assert_eq!(out, Ok(reply(StatusCode::OK, &window)));
+        assert_eq!(retries, 2);
+        // 0.5s then 1s (jitter pinned to 0), not a give-up on the 300s reset.
+        assert_eq!(started.elapsed(), Duration::from_millis(1500));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn transport_error_on_retry_is_returned() {
+        let (out, retries) = run(vec![Ok(reply(TOO_MANY, &[])), Err("reset")], true).await;
+        assert_eq!(out, Err("reset"));
+        assert_eq!(retries, 1);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn unreplayable_request_returns_first_response() {
+        let (out, retries) = run(vec![Ok(reply(TOO_MANY, &[]))], false).await;
+        assert_eq!(out, Ok(reply(TOO_MANY, &[])));
+        assert_eq!(retries, 0);
+    }
+
+    #[test]
+    fn gate_caps_requests_in_flight() {
+        let gate = HostGate::default();
+        let held: Vec<_> = (0..MAX_IN_FLIGHT_PER_HOST)
+            .map(|_| gate.0.try_acquire().unwrap())
+            .collect();
+        assert!(gate.clone().0.try_acquire().is_err());
+        drop(held);
+        assert!(gate.0.try_acquire().is_ok());
+    }
+}
@@ -5,6 +5,7 @@ use std::time::Duration;
 use async_trait::async_trait;
 use chrono::Utc;
 
+use crate::adapters::http;
 use crate::domain::entities::market_snapshot::MarketSnapshot;
 use crate::domain::entities::ticker::Ticker;
 use crate::domain::error::DomainError;
@@ -18,6 +19,7 @@ const TIMEOUT_SECS: u64 = 10;
 #[derive(Clone)]
 pub struct YahooMarketSource {
     client: reqwest::Client,
+    gate: http::HostGate,
 }
 
 impl YahooMarketSource {
@@ -30,7 +32,10 @@ impl YahooMarketSource {
                 name: "yahoo".into(),
                 message: format!("client build failed: {e}"),
             })?;
-        Ok(Self { client })
+        Ok(Self {
+            client,
+            gate: http::HostGate::default(),
+        })
     }
 
     /// Issue the chart request and return the HTTP status alongside the raw
@@ -42,10 +47,7 @@ impl YahooMarketSource {
     ) -> Result<(reqwest::StatusCode, String), DomainError> {
         let url = format!("{BASE_URL}/{}?range=3mo&interval=1d", ticker.as_str());
 
-        let resp = self
-            .client
-            .get(url)
-            .send()
+        let resp = http::send_with_retry(self.client.get(url), &self.gate)
             .await
             .map_err(|e| DomainError::SourceFailure {
                 name: "yahoo".into(),
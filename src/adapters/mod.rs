@@ -1,3 +1,4 @@
 pub mod analyzer;
+pub(crate) mod http;
 pub mod market;
 pub mod sources;
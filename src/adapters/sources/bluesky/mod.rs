@@ -8,6 +8,7 @@ use chrono::Utc;
 use secrecy::{ExposeSecret, SecretString};
 use tokio::sync::RwLock;
 
+use crate::adapters::http;
 use crate::domain::entities::social_post::SocialPost;
 use crate::domain::entities::ticker::Ticker;
 use crate::domain::error::DomainError;
@@ -23,6 +24,7 @@ pub struct BlueskySource {
     handle: String,
     app_password: SecretString,
     user_agent: String,
+    gate: http::HostGate,
     token: RwLock<Option<CachedToken>>,
 }
 
@@ -42,6 +44,7 @@ impl BlueskySource {
             handle,
             app_password,
             user_agent,
+            gate: http::HostGate::default(),
             token: RwLock::new(None),
         })
     }
@@ -101,17 +104,18 @@ impl SocialDataSource for BlueskySource {
             .append_pair("sort", "latest")
             .append_pair("limit", &limit_str);
 
-        let resp = self
-            .client
-            .get(url)
-            .bearer_auth(bearer.expose_secret())
-            .header(reqwest::header::USER_AGENT, &self.user_agent)
-            .send()
-            .await
-            .map_err(|e| DomainError::SourceFailure {
-                name: "bluesky".into(),
-                message: format!("search request failed: {e}"),
-            })?;
+        let resp = http::send_with_retry(
+            self.client
+                .get(url)
+                .bearer_auth(bearer.expose_secret())
+                .header(reqwest::header::USER_AGENT, &self.user_agent),
+            &self.gate,
+        )
+        .await
+        .map_err(|e| DomainError::SourceFailure {
+            name: "bluesky".into(),
+            message: format!("search request failed: {e}"),
+        })?;
         let status = resp.status();
         let body = resp.text().await.map_err(|e| DomainError::SourceFailure {
             name: "bluesky".into(),
@@ -11,14 +11,22 @@ use crate::domain::ports::social_data_source::SocialDataSource;
 /// Assemble the social data sources from credentials: the real `RedditSource`
 /// and `BlueskySource` when both their respective credentials are set. A partial
 /// config or constructor failure logs a warning to stderr and omits the source.
+/// `subreddits` scopes the Reddit search: `reddit::normalize_subreddits`
+/// output (empty -> the curated default list). A bad list is the caller's
+/// error to surface, so it never reaches the warn-and-omit path.
 /// Shared by both composition roots (`main.rs` and `mcp::server::serve`).
-pub fn build_social_sources(credentials: &Credentials) -> Vec<Box<dyn SocialDataSource>> {
+pub fn build_social_sources(
+    credentials: &Credentials,
+    subreddits: &[String],
+) -> Vec<Box<dyn SocialDataSource>> {
     let mut social: Vec<Box<dyn SocialDataSource>> = Vec::new();
     match (
         credentials.reddit_client_id.clone(),
         credentials.reddit_client_secret.clone(),
     ) {
-        (Some(id), Some(secret)) => match reddit::RedditSource::new(id, secret) {
+        (Some(id), Some(secret)) => match reddit::RedditSource::new(id, secret)
+            .map(|src| src.with_subreddits(subreddits))
+        {
             Ok(src) => social.push(Box::new(src)),
             Err(e) => eprintln!("warning: reddit disabled: {e}"),
         },
@@ -67,12 +75,12 @@ mod tests {
 
     #[test]
     fn no_creds_wires_no_sources() {
-        assert!(build_social_sources(&creds(false, false)).is_empty());
+        assert!(build_social_sources(&creds(false, false), &[]).is_empty());
     }
 
     #[test]
     fn includes_reddit_with_creds() {
-        let kinds: Vec<_> = build_social_sources(&creds(true, false))
+        let kinds: Vec<_> = build_social_sources(&creds(true, false), &[])
             .iter()
             .map(|s| s.kind())
             .collect();
@@ -83,12 +91,12 @@ mod tests {
     fn partial_creds_omits_reddit() {
         let mut c = creds(true, false);
         c.reddit_client_secret = None; // only the client id is set
-        assert!(build_social_sources(&c).is_empty());
+        assert!(build_social_sources(&c, &[]).is_empty());
     }
 
     #[test]
     fn includes_bluesky_with_creds() {
-        let kinds: Vec<_> = build_social_sources(&creds(false, true))
+        let kinds: Vec<_> = build_social_sources(&creds(false, true), &[])
             .iter()
             .map(|s| s.kind())
             .collect();
@@ -97,7 +105,7 @@ mod tests {
 
     #[test]
     fn includes_both_with_all_creds() {
-        let kinds: Vec<_> = build_social_sources(&creds(true, true))
+        let kinds: Vec<_> = build_social_sources(&creds(true, true), &[])
             .iter()
             .map(|s| s.kind())
             .collect();
@@ -108,6 +116,15 @@ mod tests {
     fn partial_bluesky_creds_omits_bluesky() {
         let mut c = creds(false, true);
         c.bluesky_app_password = None; // only the handle is set
-        assert!(build_social_sources(&c).is_empty());
+        assert!(build_social_sources(&c, &[]).is_empty());
+    }
+
+    #[test]
+    fn chosen_subreddits_keep_reddit() {
+        let kinds: Vec<_> = build_social_sources(&creds(true, true), &["pennystocks".into()])
+            .iter()
+            .map(|s| s.kind())
+            .collect();
+        assert_eq!(kinds, vec![SourceKind::Reddit, SourceKind::Bluesky]);
     }
 }
@@ -8,6 +8,7 @@ use chrono::Utc;
 use secrecy::{ExposeSecret, SecretString};
 use tokio::sync::RwLock;
 
+use crate::adapters::http;
 use crate::domain::entities::social_post::SocialPost;
 use crate::domain::entities::ticker::Ticker;
 use crate::domain::error::DomainError;
@@ -15,7 +16,15 @@ use crate::domain::ports::social_data_source::SocialDataSource;
 use crate::domain::values::source_kind::SourceKind;
 use auth::CachedToken;
 
-const SUBS: &str = "wallstreetbets+stocks+options+investing+StockMarket";
+/// Curated search scope when the caller doesn't choose one — finance subs
+/// where cashtags are common.
+pub const DEFAULT_SUBREDDITS: [&str; 5] = [
+    "wallstreetbets",
+    "stocks",
+    "options",
+    "investing",
+    "StockMarket",
+];
 const API_BASE: &str = "https://oauth.reddit.com";
 const TIMEOUT_SECS: u64 = 10;
 
@@ -24,9 +33,59 @@ pub struct RedditSource {
     client_id: SecretString,
     client_secret: SecretString,
     user_agent: String,
+    gate: http::HostGate,
+    /// `+`-joined subreddit path segment, e.g. `wallstreetbets+stocks`.
+    subreddits: String,
     token: RwLock<Option<CachedToken>>,
 }
 
+/// Subreddit name charset: letters, digits, underscore, 2-21 chars.
+fn is_valid_subreddit(s: &str) -> bool {
+    (2..=21).contains(&s.len()) && s.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+/// Trim, strip a leading `r/` or `/r/`, skip blanks (e.g. a trailing comma).
+/// Empty raw input -> the default list. Unlike pulse's `normalize_accounts`,
+/// which keeps the valid handles, any invalid name rejects the whole list,
+/// naming each offender: the subreddits define the sample the sentiment is
+/// computed over, so searching a subset of what the user typed would
+/// silently misreport it, while a dropped pulse account only means fewer
+/// catalyst posts.
+pub fn normalize_subreddits(raw: &[String]) -> Result<Vec<String>, DomainError> {
+    if raw.is_empty() {
+        return Ok(DEFAULT_SUBREDDITS.iter().map(|s| s.to_string()).collect());
+    }
+    let names: Vec<&str> = raw
+        .iter()
+        .map(|s| {
+            let s = s.trim();
+            let s = s.strip_prefix('/').unwrap_or(s);
+            s.strip_prefix("r/").unwrap_or(s)
+        })
+        .filter(|s| !s.is_empty())
+        .collect();
+    let rejected: Vec<&str> = names
+        .iter()
+        .copied()
+        .filter(|s| !is_valid_subreddit(s))
+        .collect();
+    if !rejected.is_empty() {
+        return Err(DomainError::SourceFailure {
+            name: "reddit".into(),
+            message: format!(
+                "invalid subreddit name(s) {rejected:?} (letters, digits, underscore, 2-21 chars)"
+            ),
+        });
+    }
+    if names.is_empty() {
+        return Err(DomainError::SourceFailure {
+            name: "reddit".into(),
+            message: format!("no subreddits in {raw:?}"),
+        });
+    }
+    Ok(names.into_iter().map(str::to_string).collect())
+}
+
 impl RedditSource {
     pub fn new(client_id: SecretString, client_secret: SecretString) -> Result<Self, DomainError> {
         let user_agent = format!(
@@ -46,10 +105,22 @@ impl RedditSource {
             client_id,
             client_secret,
             user_agent,
+            gate: http::HostGate::default(),
+            subreddits: DEFAULT_SUBREDDITS.join("+"),
             token: RwLock::new(None),
         })
     }
 
+    /// Narrow (or widen) the search scope. `names` are `normalize_subreddits`
+    /// output — validated once at the edge, like pulse's accounts before they
+    /// reach the X adapter. Empty -> keep the default list.
+    pub fn with_subreddits(mut self, names: &[String]) -> Self {
+        if !names.is_empty() {
+            self.subreddits = names.join("+");
+        }
+        self
+    }
+
     async fn ensure_token(&self) -> Result<SecretString, DomainError> {
         let now = Utc::now();
         {
@@ -93,12 +164,11 @@ impl SocialDataSource for RedditSource {
         let limit_str = limit.min(100).to_string();
         // `.query()` is behind reqwest's `query` feature, which this crate does not enable;
         // build the query string manually via the re-exported `url::Url` instead.
-        let mut url = reqwest::Url::parse(&format!("{API_BASE}/r/{SUBS}/search")).map_err(|e| {
-            DomainError::SourceFailure {
+        let mut url = reqwest::Url::parse(&format!("{API_BASE}/r/{}/search", self.subreddits))
+            .map_err(|e| DomainError::SourceFailure {
                 name: "reddit".into(),
                 message: format!("bad search url: {e}"),
-            }
-        })?;
+            })?;
         url.query_pairs_mut()
             .append_pair("q", &cashtag)
             .append_pair("restrict_sr", "1")
@@ -107,17 +177,18 @@ impl SocialDataSource for RedditSource {
             .append_pair("limit", &limit_str)
             .append_pair("raw_json", "1");
 
-        let resp = self
-            .client
-            .get(url)
-            .bearer_auth(bearer.expose_secret())
-            .header(reqwest::header::USER_AGENT, &self.user_agent)
-            .send()
-            .await
-            .map_err(|e| DomainError::SourceFailure {
-                name: "reddit".into(),
-                message: format!("search request failed: {e}"),
-            })?;
+        let resp = http::send_with_retry(
+            self.client
+                .get(url)
+                .bearer_auth(bearer.expose_secret())
+                .header(reqwest::header::USER_AGENT, &self.user_agent),
+            &self.gate,
+        )
+        .await
+        .map_err(|e| DomainError::SourceFailure {
+            name: "reddit".into(),
+            message: format!("search request failed: {e}"),
+        })?;
         let status = resp.status();
         let body = resp.text().await.map_err(|e| DomainError::SourceFailure {
             name: "reddit".into(),
@@ -152,6 +223,53 @@ mod tests {
     fn new_builds_and_kind_is_reddit() {
         let src = RedditSource::new(secret("id"), secret("sec")).unwrap();
         assert_eq!(src.kind(), SourceKind::Reddit);
+        assert_eq!(
+            src.subreddits,
+            "wallstreetbets+stocks+options+investing+StockMarket"
+        );
+    }
+
+    #[test]
+    fn with_subreddits_joins_normalized_names() {
+        let src = RedditSource::new(secret("id"), secret("sec"))
+            .unwrap()
+            .with_subreddits(
+                &normalize_subreddits(&["r/pennystocks".into(), " /r/Daytrading ".into()]).unwrap(),
+            );
+        assert_eq!(src.subreddits, "pennystocks+Daytrading");
+    }
+
+    #[test]
+    fn normalize_skips_blanks_and_falls_back_to_defaults_when_empty() {
+        let raw = vec!["stocks".to_string(), " ".to_string(), "".to_string()];
+        assert_eq!(normalize_subreddits(&raw).unwrap(), vec!["stocks"]);
+        assert_eq!(
+            normalize_subreddits(&[]).unwrap(),
+            DEFAULT_SUBREDDITS.to_vec()
+        );
+    }
+
+    #[test]
+    fn normalize_rejects_the_list_naming_each_invalid_name() {
+        let raw = vec![
+            "stocks".to_string(),
+            "wall street".to_string(), // space -> invalid
+            "a".to_string(),           // too short
+            "x".repeat(22),            // too long
+        ];
+        let err = normalize_subreddits(&raw).unwrap_err();
+        assert!(matches!(err, DomainError::SourceFailure { ref name, .. } if name == "reddit"));
+        let msg = err.to_string();
+        assert!(msg.contains("\"wall street\""), "{msg}");
+        assert!(msg.contains("\"a\""), "{msg}");
+        assert!(msg.contains(&"x".repeat(22)), "{msg}");
+        assert!(!msg.contains("stocks"), "{msg}");
+    }
+
+    #[test]
+    fn normalize_all_blank_nonempty_errors() {
+        let err = normalize_subreddits(&["r/".into(), " ".into()]).unwrap_err();
+        assert!(err.to_string().contains("no subreddits"));
     }
 
     #[tokio::test]
@@ -1,4 +1,4 @@
-use chrono::Utc;
+use chrono::{DateTime, Utc};
 use futures::future::join_all;
 
 use crate::adapters::analyzer::lexicon::LexiconAnalyzer;
@@ -13,10 +13,12 @@ use crate::domain::ports::market_data_source::MarketDataSource;
 use crate::domain::ports::post_analyzer::PostAnalyzer;
 use crate::domain::ports::social_data_source::SocialDataSource;
 
+/// `now` stamps `generated_at`; callers pass the wall clock, tests pin it.
 pub async fn analyze(
     req: &AnalysisRequest,
     social_sources: &[Box<dyn SocialDataSource>],
     market_source: Option<&dyn MarketDataSource>,
+    now: DateTime<Utc>,
 ) -> Result<SpeculationReport, DomainError> {
     let ticker = Ticker::parse(&req.ticker)?;
 
@@ -62,7 +64,6 @@ pub async fn analyze(
     let analyzer = LexiconAnalyzer::new();
     let signals = analyzer.analyze(&posts).await?;
 
-    let now = Utc::now();
     let mut report =
         SpeculationEngine::aggregate(&ticker, &posts, &signals, market.as_ref(), now, &req.engine)?;
 
@@ -78,6 +79,11 @@ mod tests {
     use crate::adapters::market::mock_market::MockMarketSource;
     use crate::adapters::sources::test_fixtures::fixture_social;
     use crate::domain::values::source_kind::SourceKind;
+    use chrono::TimeZone;
+
+    fn at() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 6, 24, 16, 0, 0).unwrap()
+    }
 
     fn req(ticker: &str, market: bool) -> AnalysisRequest {
         AnalysisRequest {
@@ -96,12 +102,14 @@ mod tests {
             &req("AAPL", true),
             &fixture_social(),
             Some(&MockMarketSource),
+            at(),
         )
         .await
         .unwrap();
         assert_eq!(report.social.total_mentions, 10);
         assert_eq!(report.fusion.alignment, Alignment::ConfirmingBullish);
         assert!(report.market.is_some());
+        assert_eq!(report.generated_at, at());
     }
 
     #[tokio::test]
@@ -109,7 +117,8 @@ mod tests {
         assert!(analyze(
             &req("$$$", true),
             &fixture_social(),
-            Some(&MockMarketSource)
+            Some(&MockMarketSource),
+            at()
         )
         .await
         .is_err());
@@ -118,7 +127,7 @@ mod tests {
     #[tokio::test]
     async fn social_only_when_no_source_provided() {
         use crate::domain::values::speculation::Alignment;
-        let report = analyze(&req("AAPL", false), &fixture_social(), None)
+        let report = analyze(&req("AAPL", false), &fixture_social(), None, at())
             .await
             .unwrap();
         assert!(report.market.is_none());
@@ -131,7 +140,9 @@ mod tests {
         let social: Vec<Box<dyn SocialDataSource>> = vec![Box::new(
             crate::adapters::sources::test_fixtures::bluesky_fixture(),
         )];
-        let report = analyze(&req("AAPL", false), &social, None).await.unwrap();
+        let report = analyze(&req("AAPL", false), &social, None, at())
+            .await
+            .unwrap();
         assert_eq!(report.social.total_mentions, 6);
         assert!(report
             .fusion
@@ -145,7 +156,7 @@ mod tests {
         // The spec's explicit edge decision: nothing configured + --no-market
         // -> DomainError::NoData (mocks used to mask this path).
         let social: Vec<Box<dyn SocialDataSource>> = vec![];
-        let err = analyze(&req("AAPL", false), &social, None)
+        let err = analyze(&req("AAPL", false), &social, None, at())
             .await
             .unwrap_err();
         assert!(matches!(err, DomainError::NoData));
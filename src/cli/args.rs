@@ -1,6 +1,8 @@
 use clap::{Parser, Subcommand, ValueEnum};
 
+use crate::adapters::sources::reddit::normalize_subreddits;
 use crate::config::settings::{AppConfig, OutputFormat};
+use crate::domain::error::DomainError;
 
 #[derive(Parser, Debug)]
 #[command(
@@ -29,6 +31,9 @@ pub enum Command {
 
     /// Deterministic risk math for one trade idea: ATR stop, budget-capped size, R targets
     Risk(RiskArgs),
+
+    /// Print a shell completion script, e.g. `openintel completions zsh > _openintel`
+    Completions(CompletionsArgs),
 }
 
 #[derive(clap::Args, Debug)]
@@ -41,6 +46,12 @@ pub struct AnalyzeArgs {
     #[arg(long)]
     pub enable_bluesky: bool,
 
+    /// Subreddits the Reddit source searches, comma-separated; an r/ prefix
+    /// is accepted.
+    /// Default: wallstreetbets, stocks, options, investing, StockMarket
+    #[arg(long, value_delimiter = ',')]
+    pub subreddits: Vec<String>,
+
     /// Skip the market snapshot (social-only report)
     #[arg(long)]
     pub no_market: bool,
@@ -134,19 +145,31 @@ pub struct RiskArgs {
     pub format: FormatArg,
 }
 
-pub fn to_app_config(args: &AnalyzeArgs) -> AppConfig {
+#[derive(clap::Args, Debug)]
+pub struct CompletionsArgs {
+    /// Shell to generate for
+    #[arg(value_enum)]
+    pub shell: clap_complete::Shell,
+}
+
+/// Errors on an invalid `--subreddits` list: the user scoped the Reddit
+/// search explicitly, so a bad list fails the command rather than quietly
+/// running without Reddit.
+pub fn to_app_config(args: &AnalyzeArgs) -> Result<AppConfig, DomainError> {
     let format = match args.format {
         FormatArg::Table => OutputFormat::Table,
         FormatArg::Json => OutputFormat::Json,
     };
-    AppConfig::new(
+    let mut config = AppConfig::new(
         args.ticker.clone(),
         args.enable_reddit,
         args.enable_bluesky,
         args.no_market,
         args.limit,
         format,
-    )
+    );
+    config.subreddits = normalize_subreddits(&args.subreddits)?;
+    Ok(config)
 }
 
 #[cfg(test)]
@@ -164,6 +187,56 @@ mod tests {
         assert_eq!(args.ticker, "AAPL");
         assert_eq!(args.format, FormatArg::Json);
         assert_eq!(args.limit, 50);
+        assert!(args.subreddits.is_empty());
+    }
+
+    #[test]
+    fn parses_analyze_with_subreddits() {
+        let cli = Cli::try_parse_from([
+            "openintel",
+            "analyze",
+            "GME",
+            "--subreddits",
+            "wallstreetbets,Superstonk",
+        ])
+        .unwrap();
+        let Command::Analyze(args) = cli.command else {
+            unreachable!()
+        };
+        assert_eq!(args.subreddits, vec!["wallstreetbets", "Superstonk"]);
+    }
+
+    #[test]
+    fn app_config_carries_normalized_subreddits() {
+        let cli = Cli::try_parse_from([
+            "openintel",
+            "analyze",
+            "GME",
+            "--subreddits",
+            "r/Superstonk,",
+        ])
+        .unwrap();
+        let Command::Analyze(args) = cli.command else {
+            unreachable!()
+        };
+        assert_eq!(to_app_config(&args).unwrap().subreddits, vec!["Superstonk"]);
+    }
+
+    #[test]
+    fn app_config_rejects_invalid_subreddits() {
+        let cli = Cli::try_parse_from([
+            "openintel",
+            "analyze",
+            "GME",
+            "--subreddits",
+            "stocks,wall street",
+        ])
+        .unwrap();
+        let Command::Analyze(args) = cli.command else {
+            unreachable!()
+        };
+        let err = to_app_config(&args).unwrap_err();
+        assert!(err.to_string().contains("\"wall street\""), "{err}");
     }
 
     #[test]
@@ -172,7 +245,7 @@ mod tests {
         let Command::Analyze(args) = cli.command else {
             unreachable!()
         };
-        let cfg = to_app_config(&args);
+        let cfg = to_app_config(&args).unwrap();
         assert_eq!(cfg.enabled_sources.len(), 2);
         assert!(cfg.market_enabled);
         assert_eq!(cfg.format, crate::config::settings::OutputFormat::Table);
@@ -298,6 +371,16 @@ mod tests {
         assert!(args.entry.is_none());
     }
 
+    #[test]
+    fn parses_completions_shell() {
+        let cli = Cli::try_parse_from(["openintel", "completions", "zsh"]).unwrap();
+        let Command::Completions(args) = cli.command else {
+            panic!("expected completions command");
+        };
+        assert_eq!(args.shell, clap_complete::Shell::Zsh);
+        assert!(Cli::try_parse_from(["openintel", "completions", "cmd"]).is_err());
+    }
+
     #[test]
     fn risk_requires_budget() {
         assert!(Cli::try_parse_from(["openintel", "risk", "NVDA"]).is_err());
@@ -0,0 +1,29 @@
+//! CLI leaf for `openintel completions` — returns the script; main prints.
+
+use clap::CommandFactory;
+use clap_complete::Shell;
+
+use crate::cli::args::Cli;
+
+pub fn render(shell: Shell) -> String {
+    let mut cmd = Cli::command();
+    let name = cmd.get_name().to_string();
+    let mut out = Vec::new();
+    clap_complete::generate(shell, &mut cmd, name, &mut out);
+    String::from_utf8_lossy(&out).into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scripts_cover_every_subcommand() {
+        for shell in [Shell::Bash, Shell::Zsh, Shell::Fish] {
+            let script = render(shell);
+            for sub in ["analyze", "mcp", "setup", "pulse", "risk", "completions"] {
+                assert!(script.contains(sub), "{shell}: missing {sub}");
+            }
+        }
+    }
+}
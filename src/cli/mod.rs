@@ -1,4 +1,5 @@
 pub mod args;
+pub mod completions;
 pub mod pulse;
 pub mod risk;
 pub mod run;
@@ -17,7 +17,8 @@ pub async fn analyze(
         limit: config.limit,
         engine: config.engine.clone(),
     };
-    let report = application::analyze(&req, social_sources, market_source).await?;
+    let report =
+        application::analyze(&req, social_sources, market_source, chrono::Utc::now()).await?;
     let rendered = render(&report, config.format);
     Ok((report, rendered))
 }
@@ -15,6 +15,9 @@ pub struct AppConfig {
     pub limit: usize,
     pub format: OutputFormat,
     pub engine: EngineConfig,
+    /// Reddit search scope, already normalized (`reddit::normalize_subreddits`).
+    /// Empty -> the source's default list.
+    pub subreddits: Vec<String>,
 }
 
 impl AppConfig {
@@ -44,6 +47,7 @@ impl AppConfig {
             limit,
             format,
             engine: EngineConfig::default(),
+            subreddits: Vec::new(),
         }
     }
 }
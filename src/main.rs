@@ -14,13 +14,22 @@ async fn main() -> ExitCode {
 
     match cli.command {
         Command::Analyze(args) => {
+            // A bad --subreddits list fails here, before any credential lookup.
+            let config = match to_app_config(&args) {
+                Ok(config) => config,
+                Err(e) => {
+                    eprintln!("error: {e}");
+                    return ExitCode::FAILURE;
+                }
+            };
             // Credentials resolve env-first, then the OS keychain (written by `openintel setup`).
             let store = KeychainStore::new();
             let credentials = Credentials::load(&store);
 
-            let config = to_app_config(&args);
-
-            let social = openintel::adapters::sources::build_social_sources(&credentials);
+            let social = openintel::adapters::sources::build_social_sources(
+                &credentials,
+                &config.subreddits,
+            );
 
             let outcome = if config.market_enabled {
                 let market = match YahooMarketSource::new() {
@@ -70,7 +79,9 @@ async fn main() -> ExitCode {
                     ExitCode::SUCCESS
                 }
                 Err(e) if e.to_string().contains("not configured") => {
-                    println!("{}", openintel::cli::pulse::not_configured_text());
+                    // stderr, like every other failure: stdout carries only the
+                    // rendered report, so `--format json` output stays parseable.
+                    eprintln!("{}", openintel::cli::pulse::not_configured_text());
                     ExitCode::FAILURE
                 }
                 Err(e) => {
@@ -89,5 +100,9 @@ async fn main() -> ExitCode {
                 ExitCode::FAILURE
             }
         },
+        Command::Completions(args) => {
+            print!("{}", openintel::cli::completions::render(args.shell));
+            ExitCode::SUCCESS
+        }
     }
 }
@@ -171,7 +171,7 @@ impl ServerHandler for OpenIntelServer {
 pub async fn serve() -> Result<(), Box<dyn std::error::Error>> {
     let store = crate::config::store::KeychainStore::new();
     let credentials = Credentials::load(&store);
-    let social = crate::adapters::sources::build_social_sources(&credentials);
+    let social = crate::adapters::sources::build_social_sources(&credentials, &[]);
 
     let market = YahooMarketSource::new()?;
     let pulse_feed = match credentials.x_bearer.clone() {
@@ -107,7 +107,8 @@ pub async fn run_analyze(
         args.no_market,
         args.limit,
     );
-    let report = application::analyze(&req, social_sources, Some(market_source)).await?;
+    let report =
+        application::analyze(&req, social_sources, Some(market_source), Utc::now()).await?;
     Ok(AnalyzeOutput {
         summary: summarize(&report),
         report,
@@ -156,9 +157,11 @@ pub async fn run_scan(
         no_market,
         limit,
     } = args;
+    // One clock per batch, so every report in it carries the same stamp.
+    let now = Utc::now();
     let futures = tickers.into_iter().map(|t| async move {
         let req = request_from(t.clone(), enable_reddit, enable_bluesky, no_market, limit);
-        match application::analyze(&req, social_sources, Some(market_source)).await {
+        match application::analyze(&req, social_sources, Some(market_source), now).await {
             Ok(report) => ScanEntry {
                 ticker: t,
                 report: Some(report),
@@ -265,11 +268,13 @@ pub async fn run_compare(
         no_market,
         limit,
     } = args;
+    // One clock per batch, so every report in it carries the same stamp.
+    let now = Utc::now();
     let futures = tickers.into_iter().map(|t| async move {
         let req = request_from(t.clone(), enable_reddit, enable_bluesky, no_market, limit);
         (
             t,
-            application::analyze(&req, social_sources, Some(market_source)).await,
+            application::analyze(&req, social_sources, Some(market_source), now).await,
         )
     });
     let results = futures::future::join_all(futures).await;
@@ -494,6 +499,29 @@ mod tests {
         assert!(out.disclaimer.contains("Not financial advice"));
     }
 
+    #[tokio::test]
+    async fn run_scan_stamps_every_report_with_one_clock() {
+        let out = run_scan(
+            ScanArgs {
+                tickers: vec!["AAPL".into(), "MSFT".into(), "NVDA".into()],
+                enable_reddit: None,
+                enable_bluesky: None,
+                no_market: None,
+                limit: None,
+            },
+            &fixture_social(),
+            &MockMarketSource,
+        )
+        .await;
+        let stamps: Vec<_> = out
+            .entries
+            .iter()
+            .map(|e| e.report.as_ref().unwrap().generated_at)
+            .collect();
+        assert_eq!(stamps.len(), 3);
+        assert!(stamps.iter().all(|t| *t == stamps[0]));
+    }
+
     #[tokio::test]
     async fn run_scan_empty_list_is_empty() {
         let out = run_scan(